@@ -0,0 +1,116 @@
+#![warn(missing_docs)]
+
+//! Ordered character sets that the Vigenère cipher shifts within.
+
+use std::collections::HashMap;
+
+/// The 26 uppercase English letters, used when no alphabet is configured explicitly.
+pub const DEFAULT: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// An ordered set of symbols defining the radix the Vigenère cipher shifts within.
+///
+/// Any character not present in the alphabet passes through the cipher unchanged, exactly as
+/// punctuation and whitespace pass through the default `A`-`Z` alphabet. Each symbol is also
+/// indexed under its other case, so that both cases of a letter resolve to the same position,
+/// unless the alphabet holds that other case as a symbol of its own.
+#[derive(Clone)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+    index_of: HashMap<char, usize>,
+}
+
+impl Alphabet {
+    /// Builds an alphabet from an ordered sequence of symbols.
+    ///
+    /// Every symbol is indexed under its literal form first, so that alphabets which contain
+    /// both cases of a letter as distinct symbols (*e.g.* a base64-style alphabet) keep them at
+    /// their own positions; only once every literal symbol has claimed its position do
+    /// case-folded aliases get filled in for characters no symbol already claims, so that a
+    /// single-case alphabet (*e.g.* the default upper-case A-Z) stays case-insensitive.
+    pub fn new(symbols: &str) -> Self {
+        let symbols: Vec<char> = symbols.chars().collect();
+        let mut index_of = HashMap::new();
+        for (position, &ch) in symbols.iter().enumerate() {
+            index_of.entry(ch).or_insert(position);
+        }
+        for (position, &ch) in symbols.iter().enumerate() {
+            for variant in ch.to_lowercase() {
+                index_of.entry(variant).or_insert(position);
+            }
+            for variant in ch.to_uppercase() {
+                index_of.entry(variant).or_insert(position);
+            }
+        }
+        Self { symbols, index_of }
+    }
+
+    /// Number of symbols in the alphabet, *i.e.* the modulus of the cipher.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Whether the alphabet has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Position (radix digit) of `ch` in the alphabet, if present.
+    pub fn position_of(&self, ch: char) -> Option<usize> {
+        self.index_of.get(&ch).copied()
+    }
+
+    /// Symbol at `position`, which must be `< self.len()`.
+    pub fn symbol_at(&self, position: usize) -> char {
+        self.symbols[position]
+    }
+}
+
+impl Default for Alphabet {
+    /// The default alphabet: the 26 uppercase English letters.
+    fn default() -> Self {
+        Self::new(DEFAULT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_is_case_insensitive() {
+        let alphabet = Alphabet::default();
+        assert_eq!(alphabet.position_of('a'), Some(0));
+        assert_eq!(alphabet.position_of('A'), Some(0));
+        assert_eq!(alphabet.position_of('z'), Some(25));
+    }
+
+    #[test]
+    fn position_of_unknown_symbol_is_none() {
+        let alphabet = Alphabet::default();
+        assert_eq!(alphabet.position_of('1'), None);
+    }
+
+    #[test]
+    fn position_of_keeps_both_cases_distinct_when_alphabet_has_both() {
+        let base64_alike = Alphabet::new(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        );
+        assert_eq!(base64_alike.position_of('A'), Some(0));
+        assert_eq!(base64_alike.position_of('a'), Some(26));
+        assert_eq!(base64_alike.position_of('Z'), Some(25));
+        assert_eq!(base64_alike.position_of('z'), Some(51));
+    }
+
+    #[test]
+    fn len_matches_symbol_count() {
+        let alphabet = Alphabet::new("0123456789");
+        assert_eq!(alphabet.len(), 10);
+        assert!(!alphabet.is_empty());
+    }
+
+    #[test]
+    fn symbol_at_round_trips_with_position_of() {
+        let alphabet = Alphabet::new("0123456789");
+        assert_eq!(alphabet.symbol_at(alphabet.position_of('7').unwrap()), '7');
+    }
+}
@@ -0,0 +1,141 @@
+#![warn(missing_docs)]
+
+//! Byte-oriented transcoding so the Vigenère cipher can protect arbitrary binary data, not just
+//! text already confined to the cipher's alphabet.
+//!
+//! Input bytes are first packed into symbols of the configured alphabet, similarly to how
+//! base64 groups 6 bits per character, run through the ordinary Vigenère transform, and unpacked
+//! back into bytes on the way out. Since the number of input bits rarely divides evenly into
+//! symbols, the last symbol is zero-padded and the padding width is recorded as a trailing
+//! decimal digit so [`decrypt`] can trim it back off.
+
+use crate::alphabet::Alphabet;
+use crate::iterator::{Decrypt, Encrypt};
+
+/// Number of bits that fit losslessly into one symbol of an alphabet with `radix` symbols.
+fn bits_per_symbol(radix: usize) -> u32 {
+    let mut bits = 0;
+    while (1usize << (bits + 1)) <= radix {
+        bits += 1;
+    }
+    bits.max(1)
+}
+
+/// Packs `data` into a string of symbols from `alphabet`, returning the symbols together with
+/// the number of padding bits zero-filled into the final symbol.
+fn pack(data: &[u8], alphabet: &Alphabet, bits_per_symbol: u32) -> (String, u32) {
+    let mut symbols = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits_in_buffer = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits_in_buffer += 8;
+        while bits_in_buffer >= bits_per_symbol {
+            bits_in_buffer -= bits_per_symbol;
+            let value = (buffer >> bits_in_buffer) & ((1 << bits_per_symbol) - 1);
+            symbols.push(alphabet.symbol_at(value as usize));
+        }
+    }
+    if bits_in_buffer == 0 {
+        return (symbols, 0);
+    }
+    let padding_bits = bits_per_symbol - bits_in_buffer;
+    let value = (buffer << padding_bits) & ((1 << bits_per_symbol) - 1);
+    symbols.push(alphabet.symbol_at(value as usize));
+    (symbols, padding_bits)
+}
+
+/// Unpacks a string of symbols produced by [`pack`] back into the original bytes.
+fn unpack(symbols: &str, alphabet: &Alphabet, bits_per_symbol: u32, padding_bits: u32) -> Vec<u8> {
+    let chars: Vec<char> = symbols.chars().collect();
+    let mut bytes = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits_in_buffer = 0;
+    for (position, &ch) in chars.iter().enumerate() {
+        let mut value = alphabet
+            .position_of(ch)
+            .unwrap_or_else(|| panic!("symbol '{}' is not part of the alphabet", ch))
+            as u32;
+        let mut bits = bits_per_symbol;
+        if position == chars.len() - 1 {
+            value >>= padding_bits;
+            bits -= padding_bits;
+        }
+        buffer = (buffer << bits) | value;
+        bits_in_buffer += bits;
+        while bits_in_buffer >= 8 {
+            bits_in_buffer -= 8;
+            bytes.push(((buffer >> bits_in_buffer) & 0xFF) as u8);
+        }
+    }
+    bytes
+}
+
+/// Encrypts arbitrary bytes with `key` over `alphabet`, returning a string of alphabet symbols
+/// followed by a single decimal digit recording how many padding bits were added to the last one.
+pub fn encrypt(data: &[u8], key: &str, alphabet: &Alphabet) -> String {
+    let bits = bits_per_symbol(alphabet.len());
+    let (symbols, padding_bits) = pack(data, alphabet, bits);
+    let cipher_text: String = symbols
+        .chars()
+        .encrypt(key, None, None, Some(alphabet), None)
+        .collect();
+    format!("{}{}", cipher_text, padding_bits)
+}
+
+/// Reverses [`encrypt`], returning the original bytes.
+pub fn decrypt(cipher_text: &str, key: &str, alphabet: &Alphabet) -> Vec<u8> {
+    let bits = bits_per_symbol(alphabet.len());
+    let mut chars: Vec<char> = cipher_text.chars().collect();
+    let padding_bits = chars
+        .pop()
+        .and_then(|ch| ch.to_digit(10))
+        .expect("cipher text is missing its padding digit");
+    let symbols: String = chars
+        .into_iter()
+        .decrypt(key, None, None, Some(alphabet), None)
+        .collect();
+    unpack(&symbols, alphabet, bits, padding_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips_arbitrary_bytes() {
+        let alphabet = Alphabet::default();
+        let data = b"\x00\x01\x02Hello, Vigenere!\xFF\xFE";
+        let cipher_text = encrypt(data, "KEY", &alphabet);
+        let plain = decrypt(&cipher_text, "KEY", &alphabet);
+        assert_eq!(plain, data);
+    }
+
+    #[test]
+    fn roundtrips_empty_input() {
+        let alphabet = Alphabet::default();
+        let cipher_text = encrypt(b"", "KEY", &alphabet);
+        let plain = decrypt(&cipher_text, "KEY", &alphabet);
+        assert_eq!(plain, b"");
+    }
+
+    #[test]
+    fn roundtrips_over_custom_alphabet() {
+        let alphabet = Alphabet::new("0123456789");
+        let data = b"binary data with a custom radix";
+        let cipher_text = encrypt(data, "37", &alphabet);
+        let plain = decrypt(&cipher_text, "37", &alphabet);
+        assert_eq!(plain, data);
+    }
+
+    #[test]
+    fn roundtrips_over_base64_style_mixed_case_alphabet() {
+        let alphabet = Alphabet::new(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        );
+        let data = b"binary data packed into a mixed-case radix";
+        let cipher_text = encrypt(data, "KEY", &alphabet);
+        let plain = decrypt(&cipher_text, "KEY", &alphabet);
+        assert_eq!(plain, data);
+    }
+}
@@ -0,0 +1,217 @@
+#![warn(missing_docs)]
+
+//! Ciphertext-only cryptanalysis of the (repeating-key, A-Z) Vigenère cipher.
+//!
+//! Recovering a key from ciphertext alone is a two-stage process: first the key *length* is
+//! estimated using the index of coincidence, then each key letter is recovered independently by
+//! treating its column as a Caesar cipher and matching it against English letter frequencies.
+
+use crate::Vigenere;
+
+/// Largest key length considered when estimating the key length.
+const MAX_KEY_LEN: usize = 20;
+
+/// Index of coincidence expected for English prose; the true key length is the smallest
+/// candidate whose average column IC is at or above this threshold.
+const ENGLISH_IC_THRESHOLD: f64 = 0.06;
+
+/// Relative frequency of each letter A-Z in English text, used for chi-squared scoring.
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
+    0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
+    0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
+];
+
+/// Recovers a likely key for `cipher_text` using index-of-coincidence analysis,
+/// returning candidate `(key, plain_text)` pairs ranked from most to least likely.
+///
+/// Only the 26 letters A-Z are considered; case is folded and non-letters are ignored for the
+/// analysis (though they are passed through unchanged in the returned plain texts).
+pub fn crack(cipher_text: &str) -> Vec<(String, String)> {
+    let letters = letters_only(cipher_text);
+    if letters.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut candidate_lengths = rank_key_lengths(&letters, MAX_KEY_LEN);
+    candidate_lengths.truncate(3);
+
+    candidate_lengths
+        .into_iter()
+        .map(|key_len| {
+            let key = solve_key_for_length(&letters, key_len);
+            let plain_text = Vigenere::new(&key).decrypt(cipher_text);
+            (key, plain_text)
+        })
+        .collect()
+}
+
+/// Recovers the single most likely key for `cipher_text`, considering key lengths up to
+/// `max_key_len`, or `None` if `cipher_text` has too few letters to give a stable index of
+/// coincidence.
+///
+/// Unlike [`crack`], this does not also decrypt `cipher_text`; callers that want the plain text
+/// too can feed the returned key into [`Vigenere::decrypt`].
+pub fn recover_key(cipher_text: &str, max_key_len: usize) -> Option<String> {
+    let letters = letters_only(cipher_text);
+    if letters.len() < 2 || max_key_len == 0 {
+        return None;
+    }
+
+    let key_len = *rank_key_lengths(&letters, max_key_len).first()?;
+    Some(solve_key_for_length(&letters, key_len))
+}
+
+/// Extracts the case-folded A-Z letters from `text`, discarding everything else.
+fn letters_only(text: &str) -> Vec<u8> {
+    text.chars()
+        .filter(|ch| ch.is_ascii_alphabetic())
+        .map(|ch| ch.to_ascii_uppercase() as u8 - b'A')
+        .collect()
+}
+
+/// Index of coincidence of a single column: `sum(n_i * (n_i - 1)) / (M * (M - 1))`.
+fn index_of_coincidence(column: &[u8]) -> f64 {
+    let m = column.len();
+    if m < 2 {
+        return 0.0;
+    }
+    let mut counts = [0usize; 26];
+    for &letter in column {
+        counts[letter as usize] += 1;
+    }
+    let numerator: usize = counts.iter().map(|&n| n * n.saturating_sub(1)).sum();
+    numerator as f64 / (m * (m - 1)) as f64
+}
+
+/// Splits `letters` into `key_len` columns, column `c` holding the letters at positions
+/// `c, c + key_len, c + 2 * key_len, ...`.
+fn columns(letters: &[u8], key_len: usize) -> Vec<Vec<u8>> {
+    let mut columns = vec![Vec::new(); key_len];
+    for (position, &letter) in letters.iter().enumerate() {
+        columns[position % key_len].push(letter);
+    }
+    columns
+}
+
+/// Ranks candidate key lengths, up to `max_len`, by their average column index of coincidence,
+/// preferring the smallest length once the average crosses the English threshold (longer
+/// multiples of the true key length also score highly, but add no new information).
+fn rank_key_lengths(letters: &[u8], max_len: usize) -> Vec<usize> {
+    let max_len = max_len.min(letters.len().max(1));
+    let mut scored: Vec<(usize, f64)> = (1..=max_len)
+        .map(|key_len| {
+            let average_ic = columns(letters, key_len)
+                .iter()
+                .map(|column| index_of_coincidence(column))
+                .sum::<f64>()
+                / key_len as f64;
+            (key_len, average_ic)
+        })
+        .collect();
+
+    scored.sort_by(|(len_a, ic_a), (len_b, ic_b)| {
+        let above_a = *ic_a >= ENGLISH_IC_THRESHOLD;
+        let above_b = *ic_b >= ENGLISH_IC_THRESHOLD;
+        match (above_a, above_b) {
+            (true, true) => len_a.cmp(len_b),
+            (false, false) => ic_b.partial_cmp(ic_a).unwrap(),
+            (above_a, above_b) => above_b.cmp(&above_a),
+        }
+    });
+
+    scored.into_iter().map(|(key_len, _)| key_len).collect()
+}
+
+/// Chi-squared statistic of a column decrypted with the given Caesar `shift`, against the
+/// expected English letter frequencies: `sum((observed - expected)^2 / expected)`.
+fn chi_squared(column: &[u8], shift: u8) -> f64 {
+    let mut counts = [0usize; 26];
+    for &letter in column {
+        let plain = (26 + letter - shift) % 26;
+        counts[plain as usize] += 1;
+    }
+    let total = column.len() as f64;
+    counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES.iter())
+        .map(|(&observed, &frequency)| {
+            let expected = frequency * total;
+            (observed as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/// Recovers the Caesar shift of a single column by minimizing the chi-squared distance to
+/// English letter frequencies.
+fn recover_shift(column: &[u8]) -> u8 {
+    (0..26)
+        .min_by(|&a, &b| chi_squared(column, a).partial_cmp(&chi_squared(column, b)).unwrap())
+        .unwrap_or(0)
+}
+
+/// Recovers a `key_len`-letter key by solving each column of `letters` independently.
+fn solve_key_for_length(letters: &[u8], key_len: usize) -> String {
+    columns(letters, key_len)
+        .iter()
+        .map(|column| (b'A' + recover_shift(column)) as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_of_coincidence_of_repeated_letter_is_one() {
+        let column = [0, 0, 0, 0];
+        assert_eq!(index_of_coincidence(&column), 1.0);
+    }
+
+    #[test]
+    fn index_of_coincidence_of_all_distinct_letters_is_low() {
+        let column: Vec<u8> = (0..26).collect();
+        assert!(index_of_coincidence(&column) < ENGLISH_IC_THRESHOLD);
+    }
+
+    #[test]
+    fn recovers_key_from_ciphertext_only() {
+        let plain_text = "THEOLDLIBRARYSTOODATTHEENDOFTHEQUIETSTREETWHERETHEMORNINGLIGHTCAMETHROUGHTHETALLWINDOWS\
+EVERYDAYTHESAMEFEWREADERSARRIVEDANDTOOKTHEIRFAVORITESEATSNEARTHEFIREPLACEANDOPENEDTHEIR\
+BOOKSWITHOUTASOUNDTHELIBRARIANWALKEDSLOWLYBETWEENTHESHELVESCHECKINGTHATEVERYVOLUMEWAS\
+RETURNEDTOITSPROPERPLACEBEFORETHEEVENINGCAMEANDTHEDOORSWERELOCKEDFORTHENIGHTAGAIN";
+        let cipher_text = Vigenere::new("LEMON").encrypt(plain_text);
+
+        let candidates = crack(&cipher_text);
+        assert!(!candidates.is_empty());
+        let (_, recovered_plain_text) = &candidates[0];
+        assert_eq!(recovered_plain_text, plain_text);
+    }
+
+    #[test]
+    fn crack_of_too_short_text_returns_no_candidates() {
+        assert_eq!(crack("A"), Vec::new());
+    }
+
+    #[test]
+    fn recover_key_recovers_known_key() {
+        let plain_text = "THEOLDLIBRARYSTOODATTHEENDOFTHEQUIETSTREETWHERETHEMORNINGLIGHTCAMETHROUGHTHETALLWINDOWS\
+EVERYDAYTHESAMEFEWREADERSARRIVEDANDTOOKTHEIRFAVORITESEATSNEARTHEFIREPLACEANDOPENEDTHEIR\
+BOOKSWITHOUTASOUNDTHELIBRARIANWALKEDSLOWLYBETWEENTHESHELVESCHECKINGTHATEVERYVOLUMEWAS\
+RETURNEDTOITSPROPERPLACEBEFORETHEEVENINGCAMEANDTHEDOORSWERELOCKEDFORTHENIGHTAGAIN";
+        let cipher_text = Vigenere::new("LEMON").encrypt(plain_text);
+
+        assert_eq!(recover_key(&cipher_text, MAX_KEY_LEN), Some("LEMON".to_string()));
+    }
+
+    #[test]
+    fn recover_key_of_too_short_text_returns_none() {
+        assert_eq!(recover_key("A", MAX_KEY_LEN), None);
+    }
+
+    #[test]
+    fn recover_key_respects_max_key_len_cap() {
+        let cipher_text = Vigenere::new("LEMON").encrypt("THISISALONGENOUGHPLAINTEXTFORASTABLEINDEXOFCOINCIDENCE");
+        assert_eq!(recover_key(&cipher_text, 0), None);
+    }
+}
@@ -0,0 +1,344 @@
+#![warn(missing_docs)]
+
+//! Streaming [`std::io`] adapters for the Vigenère cipher, for encrypting or decrypting data
+//! that does not fit in memory all at once.
+//!
+//! Unlike [`crate::iterator::VigenereIterator`], these adapters only work on the 26-letter
+//! A-Z/a-z alphabet and operate on raw bytes rather than `char`s, since a `Read`/`Write` stream
+//! is a stream of bytes, not of already-decoded characters.
+
+use std::io::{self, Read, Write};
+
+use crate::iterator::NonLetterMode;
+
+/// Type of operation of a streaming adapter.
+enum Direction {
+    /// Decrypt the stream of bytes.
+    Decrypt,
+    /// Encrypt the stream of bytes.
+    Encrypt,
+}
+
+/// Key distances and the running position into them, carried across `read`/`write` calls so
+/// that chunk boundaries never change the output.
+struct KeyState {
+    distances: Vec<u8>,
+    index: usize,
+    none_letter_mode: NonLetterMode,
+}
+
+impl KeyState {
+    /// # Panics
+    ///
+    /// Panics if `key` has no ASCII letters, since [`Self::apply`] needs at least one distance
+    /// to cycle through.
+    fn new(key: &str, none_letter_mode: NonLetterMode) -> Self {
+        let distances: Vec<u8> = key
+            .chars()
+            .filter(|ch| ch.is_ascii_alphabetic())
+            .map(|ch| ch.to_ascii_uppercase() as u8 - b'A')
+            .collect();
+        assert!(!distances.is_empty(), "key contains no ASCII letters");
+        Self {
+            distances,
+            index: 0,
+            none_letter_mode,
+        }
+    }
+
+    /// Shifts a single byte, advancing the key position when the byte is an ASCII letter.
+    /// A non-letter byte is passed through unchanged under [`NonLetterMode::Keep`], or dropped
+    /// (signalled by `None`) under [`NonLetterMode::Skip`].
+    fn shift(&mut self, byte: u8, direction: &Direction) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(self.apply(byte, b'A', direction)),
+            b'a'..=b'z' => Some(self.apply(byte, b'a', direction)),
+            _ if self.none_letter_mode == NonLetterMode::Skip => None,
+            other => Some(other),
+        }
+    }
+
+    fn apply(&mut self, byte: u8, base: u8, direction: &Direction) -> u8 {
+        let distance = self.distances[self.index];
+        self.index = (self.index + 1) % self.distances.len();
+        let input = byte - base;
+        let output = match direction {
+            Direction::Encrypt => (input + distance) % 26,
+            Direction::Decrypt => (26 + input - distance) % 26,
+        };
+        base + output
+    }
+
+    fn shift_all(&mut self, bytes: &[u8], direction: &Direction) -> Vec<u8> {
+        bytes.iter().filter_map(|&byte| self.shift(byte, direction)).collect()
+    }
+
+    /// Shifts the first `len` bytes of `bytes` in place, compacting out any byte dropped under
+    /// [`NonLetterMode::Skip`], and returns how many bytes remain at the front of the slice.
+    fn shift_in_place(&mut self, bytes: &mut [u8], len: usize, direction: &Direction) -> usize {
+        let mut out = 0;
+        for i in 0..len {
+            if let Some(byte) = self.shift(bytes[i], direction) {
+                bytes[out] = byte;
+                out += 1;
+            }
+        }
+        out
+    }
+}
+
+/// Writer adapter that encrypts bytes using the Vigenère cipher before forwarding them to the
+/// wrapped writer.
+pub struct EncryptWriter<W: Write> {
+    inner: W,
+    key: KeyState,
+}
+
+impl<W: Write> EncryptWriter<W> {
+    /// Wraps `inner`, encrypting everything written to the result with `key` before it reaches
+    /// `inner`; non-letter bytes follow `none_letter_mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no ASCII letters.
+    pub fn new(inner: W, key: &str, none_letter_mode: NonLetterMode) -> Self {
+        Self {
+            inner,
+            key: KeyState::new(key, none_letter_mode),
+        }
+    }
+}
+
+impl<W: Write> Write for EncryptWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let cipher_text = self.key.shift_all(buf, &Direction::Encrypt);
+        self.inner.write_all(&cipher_text)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writer adapter that decrypts bytes using the Vigenère cipher before forwarding them to the
+/// wrapped writer.
+pub struct DecryptWriter<W: Write> {
+    inner: W,
+    key: KeyState,
+}
+
+impl<W: Write> DecryptWriter<W> {
+    /// Wraps `inner`, decrypting everything written to the result with `key` before it reaches
+    /// `inner`; non-letter bytes follow `none_letter_mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no ASCII letters.
+    pub fn new(inner: W, key: &str, none_letter_mode: NonLetterMode) -> Self {
+        Self {
+            inner,
+            key: KeyState::new(key, none_letter_mode),
+        }
+    }
+}
+
+impl<W: Write> Write for DecryptWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let plain_text = self.key.shift_all(buf, &Direction::Decrypt);
+        self.inner.write_all(&plain_text)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader adapter that encrypts bytes, using the Vigenère cipher, as they are read from the
+/// wrapped reader.
+pub struct EncryptReader<R: Read> {
+    inner: R,
+    key: KeyState,
+}
+
+impl<R: Read> EncryptReader<R> {
+    /// Wraps `inner`, encrypting everything read from the result with `key`; non-letter bytes
+    /// follow `none_letter_mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no ASCII letters.
+    pub fn new(inner: R, key: &str, none_letter_mode: NonLetterMode) -> Self {
+        Self {
+            inner,
+            key: KeyState::new(key, none_letter_mode),
+        }
+    }
+}
+
+impl<R: Read> Read for EncryptReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let read = self.inner.read(buf)?;
+            if read == 0 {
+                return Ok(0);
+            }
+            let out = self.key.shift_in_place(buf, read, &Direction::Encrypt);
+            if out > 0 {
+                return Ok(out);
+            }
+        }
+    }
+}
+
+/// Reader adapter that decrypts bytes, using the Vigenère cipher, as they are read from the
+/// wrapped reader.
+pub struct DecryptReader<R: Read> {
+    inner: R,
+    key: KeyState,
+}
+
+impl<R: Read> DecryptReader<R> {
+    /// Wraps `inner`, decrypting everything read from the result with `key`; non-letter bytes
+    /// follow `none_letter_mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no ASCII letters.
+    pub fn new(inner: R, key: &str, none_letter_mode: NonLetterMode) -> Self {
+        Self {
+            inner,
+            key: KeyState::new(key, none_letter_mode),
+        }
+    }
+}
+
+impl<R: Read> Read for DecryptReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let read = self.inner.read(buf)?;
+            if read == 0 {
+                return Ok(0);
+            }
+            let out = self.key.shift_in_place(buf, read, &Direction::Decrypt);
+            if out > 0 {
+                return Ok(out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_writer_main_example() {
+        let mut cipher_text = Vec::new();
+        {
+            let mut writer = EncryptWriter::new(&mut cipher_text, "WHYRUST", NonLetterMode::Keep);
+            writer.write_all(b"TO EMPOWER EVERYONE").unwrap();
+        }
+        assert_eq!(cipher_text, b"PV CDJGPAY CMYJRKUC");
+    }
+
+    #[test]
+    fn decrypt_reader_main_example() {
+        let cipher_text: &[u8] = b"PV CDJGPAY CMYJRKUC";
+        let mut reader = DecryptReader::new(cipher_text, "WHYRUST", NonLetterMode::Keep);
+        let mut plain_text = String::new();
+        reader.read_to_string(&mut plain_text).unwrap();
+        assert_eq!(plain_text, "TO EMPOWER EVERYONE");
+    }
+
+    #[test]
+    fn encrypt_writer_carries_key_index_across_chunk_boundaries() {
+        let mut chunked = Vec::new();
+        {
+            let mut writer = EncryptWriter::new(&mut chunked, "WHYRUST", NonLetterMode::Keep);
+            writer.write_all(b"TO EMPOWER ").unwrap();
+            writer.flush().unwrap();
+            writer.write_all(b"EVERYONE").unwrap();
+        }
+
+        let mut whole = Vec::new();
+        {
+            let mut writer = EncryptWriter::new(&mut whole, "WHYRUST", NonLetterMode::Keep);
+            writer.write_all(b"TO EMPOWER EVERYONE").unwrap();
+        }
+
+        assert_eq!(chunked, whole);
+        assert_eq!(chunked, b"PV CDJGPAY CMYJRKUC");
+    }
+
+    #[test]
+    fn decrypt_reader_carries_key_index_across_chunk_boundaries() {
+        let cipher_text: &[u8] = b"PV CDJGPAY CMYJRKUC";
+        let mut reader = DecryptReader::new(cipher_text, "WHYRUST", NonLetterMode::Keep);
+
+        let mut first = [0u8; 4];
+        reader.read_exact(&mut first).unwrap();
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+
+        let mut plain_text = first.to_vec();
+        plain_text.extend(rest);
+        assert_eq!(plain_text, b"TO EMPOWER EVERYONE");
+    }
+
+    #[test]
+    fn encrypt_decrypt_writer_roundtrip() {
+        let mut cipher_text = Vec::new();
+        EncryptWriter::new(&mut cipher_text, "LEMON", NonLetterMode::Keep)
+            .write_all(b"ATTACK AT DAWN")
+            .unwrap();
+
+        let mut plain_text = Vec::new();
+        DecryptWriter::new(&mut plain_text, "LEMON", NonLetterMode::Keep)
+            .write_all(&cipher_text)
+            .unwrap();
+
+        assert_eq!(plain_text, b"ATTACK AT DAWN");
+    }
+
+    #[test]
+    fn key_with_non_letter_characters_is_filtered_not_miscomputed() {
+        let mut with_punctuation = Vec::new();
+        EncryptWriter::new(&mut with_punctuation, "KEY!", NonLetterMode::Keep)
+            .write_all(b"ATTACK AT DAWN")
+            .unwrap();
+
+        let mut without_punctuation = Vec::new();
+        EncryptWriter::new(&mut without_punctuation, "KEY", NonLetterMode::Keep)
+            .write_all(b"ATTACK AT DAWN")
+            .unwrap();
+
+        assert_eq!(with_punctuation, without_punctuation);
+    }
+
+    #[test]
+    #[should_panic(expected = "key contains no ASCII letters")]
+    fn key_with_no_ascii_letters_panics() {
+        let mut out = Vec::new();
+        EncryptWriter::new(&mut out, "123", NonLetterMode::Keep);
+    }
+
+    #[test]
+    fn encrypt_writer_skips_non_letters_under_skip_mode() {
+        let mut cipher_text = Vec::new();
+        EncryptWriter::new(&mut cipher_text, "WHYRUST", NonLetterMode::Skip)
+            .write_all(b"TO EMPOWER EVERYONE")
+            .unwrap();
+        assert_eq!(cipher_text, b"PVCDJGPAYCMYJRKUC");
+    }
+
+    #[test]
+    fn decrypt_reader_skips_non_letters_under_skip_mode() {
+        let cipher_text: &[u8] = b"PV CDJGPAY CMYJRKUC";
+        let mut reader = DecryptReader::new(cipher_text, "WHYRUST", NonLetterMode::Skip);
+        let mut plain_text = String::new();
+        reader.read_to_string(&mut plain_text).unwrap();
+        assert_eq!(plain_text, "TOEMPOWEREVERYONE");
+    }
+}
@@ -2,6 +2,10 @@
 
 //! Iterator-based implementation of the Vigenère cipher.
 
+use std::iter::FusedIterator;
+
+use crate::alphabet::Alphabet;
+
 /// Type of operation of the character iterator.
 enum VigenereMode {
     /// Decrypt the stream of characters.
@@ -15,7 +19,7 @@ enum VigenereMode {
 pub enum ForceCase {
     /// Keep the case of the characters.
     Keep,
-    /// Force the character to lower case.  
+    /// Force the character to lower case.
     ToLower,
     /// Force the character to upper case.
     ToUpper,
@@ -30,42 +34,95 @@ pub enum NonLetterMode {
     Skip,
 }
 
+/// Source of the key distances applied to each letter.
+#[derive(PartialEq)]
+pub enum KeyMode {
+    /// Cycle through the key once it has been fully consumed (the classic Vigenère cipher).
+    Repeating,
+    /// Use the key only as a primer: once exhausted, the key stream continues with the plain
+    /// text itself, offset by the primer's length. Since decryption discovers plain text letters
+    /// as it goes, this mode is inherently sequential and does not support reverse iteration.
+    Autokey,
+    /// Use the key as one long, non-repeating key stream (*e.g.* a passage of running text).
+    /// Exhausting it before the wrapped iterator is exhausted is an error.
+    RunningKey,
+}
+
 /// Iterator, over characters, that encrypts or decrypts the character using the Vigenère cipher.
+///
+/// Reverse iteration (see the [`DoubleEndedIterator`] implementation) needs to know how many
+/// letters precede a character counting from the *end* of the stream, which a forward-only
+/// iterator cannot answer without looking ahead. The first call to `next_back` therefore drains
+/// the remainder of the wrapped iterator into `tail`, a small one-time cost that then lets both
+/// ends be consumed in lock step without ever shifting a letter twice.
 pub struct VigenereIterator<I>
 where
     I: Iterator<Item = char>,
 {
     mode: VigenereMode,
-    distances: Vec<u8>,
+    alphabet: Alphabet,
+    distances: Vec<usize>,
+    key_mode: KeyMode,
     force_case: ForceCase,
     none_letter_mode: NonLetterMode,
-    index: usize,
+    /// Number of letters already yielded from the front, used to pick the key distance.
+    front_letters: usize,
+    /// [`KeyMode::Autokey`] feedback: the alphabet position of every plain-text letter produced
+    /// so far (the input letter when encrypting, the recovered output letter when decrypting),
+    /// indexed by letter position once the primer is exhausted.
+    plain_history: Vec<usize>,
     iter: I,
+    /// Remaining characters, materialized once reverse iteration starts. `letter_prefix[i]`
+    /// holds the number of letters in `tail[..i]`, so the absolute letter index of `tail[pos]`
+    /// is `tail_base_letters + letter_prefix[pos]`.
+    tail: Option<Vec<char>>,
+    letter_prefix: Vec<usize>,
+    tail_base_letters: usize,
+    front_pos: usize,
+    back_pos: usize,
 }
 
 impl<I> VigenereIterator<I>
 where
     I: Iterator<Item = char>,
 {
-    const UPPER_BASE: u8 = 'A' as u8;
-    const LOWER_BASE: u8 = 'a' as u8;
-
-    fn new(mode: VigenereMode, key: &str, iter: I) -> Self {
-        let distances = key
-            .chars()
-            .map(|ch| ch.to_uppercase().next().unwrap())
-            .map(|ch| ch as u8 - Self::UPPER_BASE)
-            .collect::<Vec<u8>>();
+    /// # Panics
+    ///
+    /// Panics if `key` resolves to no positions at all in `alphabet` (*e.g.* a letter-only key
+    /// against a digit-only alphabet), since [`Self::key_distance`] needs at least one distance
+    /// to cycle through regardless of [`KeyMode`].
+    fn new(mode: VigenereMode, key: &str, alphabet: Alphabet, iter: I) -> Self {
+        let distances = Self::resolve_distances(&alphabet, key);
+        assert!(
+            !distances.is_empty(),
+            "key contains no characters present in the alphabet"
+        );
         Self {
             mode,
+            alphabet,
             distances,
+            key_mode: KeyMode::Repeating,
             force_case: ForceCase::Keep,
             none_letter_mode: NonLetterMode::Keep,
-            index: 0,
+            front_letters: 0,
+            plain_history: Vec::new(),
             iter,
+            tail: None,
+            letter_prefix: Vec::new(),
+            tail_base_letters: 0,
+            front_pos: 0,
+            back_pos: 0,
         }
     }
 
+    /// Resolves every key character to its position in the alphabet, skipping characters that
+    /// aren't part of it exactly as the `next`/`next_back` transform logic skips them in the
+    /// text being en/decrypted — this matters most for [`KeyMode::RunningKey`], whose key is
+    /// typically a passage of running text with spaces and punctuation rather than a single word.
+    fn resolve_distances(alphabet: &Alphabet, key: &str) -> Vec<usize> {
+        key.chars().filter_map(|ch| alphabet.position_of(ch)).collect()
+    }
+
     fn with_force_case(self, force_case: ForceCase) -> Self {
         Self {
             force_case,
@@ -79,6 +136,77 @@ where
             ..self
         }
     }
+
+    fn with_key_mode(self, key_mode: KeyMode) -> Self {
+        Self { key_mode, ..self }
+    }
+
+    /// Picks the key distance for the letter at `letter_index`, per [`KeyMode`].
+    ///
+    /// Only [`KeyMode::Repeating`] and [`KeyMode::RunningKey`] are order-independent (the
+    /// distance depends solely on `letter_index`), which is what lets [`DoubleEndedIterator`]
+    /// compute a reverse letter's distance directly; [`KeyMode::Autokey`] instead looks up
+    /// `plain_history`, which only holds entries for letters already produced from the front, so
+    /// it must not be reached from `next_back`.
+    fn key_distance(&self, letter_index: usize) -> usize {
+        let primer_len = self.distances.len();
+        match self.key_mode {
+            KeyMode::Repeating => self.distances[letter_index % primer_len],
+            KeyMode::RunningKey => *self.distances.get(letter_index).unwrap_or_else(|| {
+                panic!(
+                    "running key exhausted after {} letters; provide a longer key text",
+                    primer_len
+                )
+            }),
+            KeyMode::Autokey if letter_index < primer_len => self.distances[letter_index],
+            KeyMode::Autokey => self.plain_history[letter_index - primer_len],
+        }
+    }
+
+    /// Encrypts or decrypts a single letter already known to be at the given absolute letter
+    /// index (*i.e.*, the count of letters preceding it), picking the matching key distance.
+    ///
+    /// The alphabet's own symbol is not assumed to already be upper or lower case (a custom
+    /// alphabet may be built from lowercase symbols), so both cased forms are derived from it
+    /// with `char::to_uppercase`/`to_lowercase` rather than treated as already-cased ASCII.
+    /// Those iterators can yield more than one code point (*e.g.* `'ß'.to_uppercase()` is `"SS"`);
+    /// since the cipher is 1:1 on characters, only the first code point is kept.
+    ///
+    /// [`ForceCase`] is skipped entirely when the two cased forms resolve to *different*
+    /// alphabet positions (*e.g.* a base64-style alphabet, where `'A'` and `'a'` are distinct
+    /// symbols rather than two spellings of the same letter): re-casing would silently swap in
+    /// whatever symbol happens to occupy the other position, corrupting the output. The literal
+    /// symbol at `output` is returned unchanged in that case.
+    ///
+    /// In [`KeyMode::Autokey`], `plain_history` is extended with the newly produced plain-text
+    /// letter's position so that it is available once the primer is exhausted; for encryption
+    /// that is `input`, for decryption it is the letter just recovered.
+    fn transform(&mut self, ch: char, letter_index: usize, input: usize) -> char {
+        let radix = self.alphabet.len();
+        let distance = self.key_distance(letter_index);
+        let output = match self.mode {
+            VigenereMode::Encrypt => (input + distance) % radix,
+            VigenereMode::Decrypt => (radix + input - distance) % radix,
+        };
+        if self.key_mode == KeyMode::Autokey {
+            let plain_position = match self.mode {
+                VigenereMode::Encrypt => input,
+                VigenereMode::Decrypt => output,
+            };
+            self.plain_history.push(plain_position);
+        }
+        let symbol = self.alphabet.symbol_at(output);
+        let upper = symbol.to_uppercase().next().unwrap_or(symbol);
+        let lower = symbol.to_lowercase().next().unwrap_or(symbol);
+        if self.alphabet.position_of(upper) != self.alphabet.position_of(lower) {
+            return symbol;
+        }
+        match self.force_case {
+            ForceCase::ToUpper => upper,
+            ForceCase::ToLower => lower,
+            ForceCase::Keep => if ch.is_lowercase() { lower } else { upper },
+        }
+    }
 }
 
 impl<I> Iterator for VigenereIterator<I>
@@ -89,67 +217,152 @@ where
 
     fn next(&mut self) -> Option<char> {
         loop {
-            break match self.iter.next() {
-                Some(ch) if ch >= 'A' && ch <= 'Z' => {
-                    let distance = self.distances[self.index];
-                    self.index = (self.index + 1) % self.distances.len();
-                    let input = ch as u8 - Self::UPPER_BASE;
-                    let output = match self.mode {
-                        VigenereMode::Encrypt => (input + distance) % 26,
-                        VigenereMode::Decrypt => (26 + input - distance) % 26,
-                    };
-                    Some(if self.force_case == ForceCase::ToLower {
-                        Self::LOWER_BASE + output
-                    } else {
-                        Self::UPPER_BASE + output
-                    } as char)
-                }
-    
-                Some(ch) if ch >= 'a' && ch <= 'z' => {
-                    let distance = self.distances[self.index];
-                    self.index = (self.index + 1) % self.distances.len();
-                    let input = ch as u8 - Self::LOWER_BASE;
-                    let output = match self.mode {
-                        VigenereMode::Encrypt => (input + distance) % 26,
-                        VigenereMode::Decrypt => (26 + input - distance) % 26,
-                    };
-                    Some(if self.force_case == ForceCase::ToUpper {
-                        Self::UPPER_BASE + output
-                    } else {
-                        Self::LOWER_BASE + output
-                    } as char)
-                }
-    
-                Some(ch) => if self.none_letter_mode == NonLetterMode::Skip {
-                    continue;
-                } else {
+            let ch = match &self.tail {
+                Some(tail) if self.front_pos < self.back_pos => {
+                    let ch = tail[self.front_pos];
+                    self.front_pos += 1;
                     Some(ch)
                 }
+                Some(_) => None,
+                None => self.iter.next(),
+            };
+            break match ch {
+                Some(ch) => match self.alphabet.position_of(ch) {
+                    Some(input) => {
+                        let letter_index = self.front_letters;
+                        self.front_letters += 1;
+                        Some(self.transform(ch, letter_index, input))
+                    }
+                    None if self.none_letter_mode == NonLetterMode::Skip => continue,
+                    None => Some(ch),
+                }
                 None => None,
             };
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match &self.tail {
+            Some(_) => {
+                let remaining = self.back_pos - self.front_pos;
+                (remaining, Some(remaining))
+            }
+            None => self.iter.size_hint(),
+        };
+        match self.none_letter_mode {
+            NonLetterMode::Keep => (lower, upper),
+            NonLetterMode::Skip => (0, upper),
+        }
+    }
+}
+
+impl<I> FusedIterator for VigenereIterator<I> where I: FusedIterator<Item = char> {}
+
+// Deliberately no `impl ExactSizeIterator for VigenereIterator`: under `NonLetterMode::Skip`,
+// how many of the remaining wrapped characters are letters isn't knowable without consuming
+// them, so no `len()` here could satisfy the trait's "exact count" contract for every mode.
+// `size_hint` (above) remains the only, inexact-allowed, signal for remaining length.
+
+impl<I> DoubleEndedIterator for VigenereIterator<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator<Item = char>,
+{
+    fn next_back(&mut self) -> Option<char> {
+        assert!(
+            self.key_mode != KeyMode::Autokey,
+            "reverse iteration is not supported in KeyMode::Autokey, whose key stream depends on \
+             letters already produced from the front"
+        );
+        if self.tail.is_none() {
+            self.materialize_tail();
+        }
+        loop {
+            if self.front_pos >= self.back_pos {
+                return None;
+            }
+            self.back_pos -= 1;
+            let pos = self.back_pos;
+            let ch = self.tail.as_ref().unwrap()[pos];
+            break match self.alphabet.position_of(ch) {
+                Some(input) => {
+                    let letter_index = self.tail_base_letters + self.letter_prefix[pos];
+                    Some(self.transform(ch, letter_index, input))
+                }
+                None if self.none_letter_mode == NonLetterMode::Skip => continue,
+                None => Some(ch),
+            };
+        }
+    }
+}
+
+impl<I> VigenereIterator<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator<Item = char>,
+{
+    /// Drains the rest of the wrapped iterator into `tail` and precomputes, for every position,
+    /// how many letters precede it, so that `next_back` can look up a key distance in O(1).
+    fn materialize_tail(&mut self) {
+        let mut tail = Vec::with_capacity(self.iter.len());
+        while let Some(ch) = self.iter.next_back() {
+            tail.push(ch);
+        }
+        tail.reverse();
+
+        let mut letter_prefix = Vec::with_capacity(tail.len());
+        let mut count = 0;
+        for &ch in &tail {
+            letter_prefix.push(count);
+            if self.alphabet.position_of(ch).is_some() {
+                count += 1;
+            }
+        }
+
+        self.back_pos = tail.len();
+        self.tail_base_letters = self.front_letters;
+        self.letter_prefix = letter_prefix;
+        self.tail = Some(tail);
+    }
 }
 
 /// Encryption trait for a character iterator.
 pub trait Encrypt: Iterator<Item = char> + Sized {
     /// Encrypt characters using the specified key and configuration.
+    ///
+    /// Under [`KeyMode::RunningKey`], `key` is the whole (non-repeating) key stream rather than a
+    /// short repeated key; under [`KeyMode::Autokey`] it is just the primer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no character present in `alphabet` (or the default A-Z alphabet, if
+    /// `alphabet` is `None`).
     fn encrypt(
         self,
         key: &str,
         force_case: Option<ForceCase>,
         none_letter_mode: Option<NonLetterMode>,
+        alphabet: Option<&Alphabet>,
+        key_mode: Option<KeyMode>,
     ) -> VigenereIterator<Self>;
 }
 
 /// Decryption trait for a character iterator.
 pub trait Decrypt: Iterator<Item = char> + Sized {
     /// Decrypt characters using the specified key and configuration.
+    ///
+    /// Under [`KeyMode::RunningKey`], `key` is the whole (non-repeating) key stream rather than a
+    /// short repeated key; under [`KeyMode::Autokey`] it is just the primer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` has no character present in `alphabet` (or the default A-Z alphabet, if
+    /// `alphabet` is `None`).
     fn decrypt(
         self,
         key: &str,
         force_case: Option<ForceCase>,
         none_letter_mode: Option<NonLetterMode>,
+        alphabet: Option<&Alphabet>,
+        key_mode: Option<KeyMode>,
     ) -> VigenereIterator<Self>;
 }
 
@@ -162,14 +375,24 @@ where
         key: &str,
         force_case: Option<ForceCase>,
         none_letter_mode: Option<NonLetterMode>,
+        alphabet: Option<&Alphabet>,
+        key_mode: Option<KeyMode>,
     ) -> VigenereIterator<I> {
-        let mut vig_iter = VigenereIterator::new(VigenereMode::Encrypt, key, self);
+        let mut vig_iter = VigenereIterator::new(
+            VigenereMode::Encrypt,
+            key,
+            alphabet.cloned().unwrap_or_default(),
+            self,
+        );
         if let Some(force_case) = force_case {
             vig_iter = vig_iter.with_force_case(force_case);
         }
         if let Some(none_letter_mode) = none_letter_mode {
             vig_iter = vig_iter.with_none_letter_mode(none_letter_mode);
         }
+        if let Some(key_mode) = key_mode {
+            vig_iter = vig_iter.with_key_mode(key_mode);
+        }
         vig_iter
     }
 }
@@ -183,14 +406,24 @@ where
         key: &str,
         force_case: Option<ForceCase>,
         none_letter_mode: Option<NonLetterMode>,
+        alphabet: Option<&Alphabet>,
+        key_mode: Option<KeyMode>,
     ) -> VigenereIterator<I> {
-        let mut vig_iter = VigenereIterator::new(VigenereMode::Decrypt, key, self);
+        let mut vig_iter = VigenereIterator::new(
+            VigenereMode::Decrypt,
+            key,
+            alphabet.cloned().unwrap_or_default(),
+            self,
+        );
         if let Some(force_case) = force_case {
             vig_iter = vig_iter.with_force_case(force_case);
         }
         if let Some(none_letter_mode) = none_letter_mode {
             vig_iter = vig_iter.with_none_letter_mode(none_letter_mode);
         }
+        if let Some(key_mode) = key_mode {
+            vig_iter = vig_iter.with_key_mode(key_mode);
+        }
         vig_iter
     }
 }
@@ -203,7 +436,7 @@ mod tests {
     fn identity_encrypt_upper_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("A", None, None);
+        let mut iter_out = iter_in.encrypt("A", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -213,7 +446,7 @@ mod tests {
     fn identity_encrypt_lower_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("a", None, None);
+        let mut iter_out = iter_in.encrypt("a", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -223,7 +456,7 @@ mod tests {
     fn identity_encrypt_upper_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("A", None, None);
+        let mut iter_out = iter_in.encrypt("A", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -233,7 +466,7 @@ mod tests {
     fn identity_encrypt_lower_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("a", None, None);
+        let mut iter_out = iter_in.encrypt("a", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -243,7 +476,7 @@ mod tests {
     fn minimal_encrypt_upper_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("B", None, None);
+        let mut iter_out = iter_in.encrypt("B", None, None, None, None);
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), Some('J'));
         assert_eq!(iter_out.next(), None);
@@ -253,7 +486,7 @@ mod tests {
     fn minimal_encrypt_lower_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("b", None, None);
+        let mut iter_out = iter_in.encrypt("b", None, None, None, None);
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), Some('J'));
         assert_eq!(iter_out.next(), None);
@@ -263,7 +496,7 @@ mod tests {
     fn minimal_encrypt_upper_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("B", None, None);
+        let mut iter_out = iter_in.encrypt("B", None, None, None, None);
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), None);
@@ -273,7 +506,7 @@ mod tests {
     fn minimal_encrypt_lower_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("b", None, None);
+        let mut iter_out = iter_in.encrypt("b", None, None, None, None);
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), None);
@@ -283,7 +516,7 @@ mod tests {
     fn bigger_encrypt() {
         let s = "HiHi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("ABC", None, None);
+        let mut iter_out = iter_in.encrypt("ABC", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), Some('J'));
@@ -295,7 +528,7 @@ mod tests {
     fn bigger_encrypt_force_lower() {
         let s = "HiHi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("ABC", Some(ForceCase::ToLower), None);
+        let mut iter_out = iter_in.encrypt("ABC", Some(ForceCase::ToLower), None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), Some('j'));
@@ -307,7 +540,7 @@ mod tests {
     fn bigger_encrypt_force_upper() {
         let s = "HiHi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("ABC", Some(ForceCase::ToUpper), None);
+        let mut iter_out = iter_in.encrypt("ABC", Some(ForceCase::ToUpper), None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('J'));
         assert_eq!(iter_out.next(), Some('J'));
@@ -319,7 +552,7 @@ mod tests {
     fn bigger_encrypt_skip_nonletters() {
         let s = "H-i H+i";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.encrypt("ABC", None, Some(NonLetterMode::Skip));
+        let mut iter_out = iter_in.encrypt("ABC", None, Some(NonLetterMode::Skip), None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), Some('J'));
@@ -331,7 +564,7 @@ mod tests {
     fn identity_decrypt_upper_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("A", None, None);
+        let mut iter_out = iter_in.decrypt("A", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -341,7 +574,7 @@ mod tests {
     fn identity_decrypt_lower_key_upper_input() {
         let s = "HI";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("a", None, None);
+        let mut iter_out = iter_in.decrypt("a", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -351,7 +584,7 @@ mod tests {
     fn identity_decrypt_upper_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("A", None, None);
+        let mut iter_out = iter_in.decrypt("A", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -361,7 +594,7 @@ mod tests {
     fn identity_decrypt_lower_key_lower_input() {
         let s = "hi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("a", None, None);
+        let mut iter_out = iter_in.decrypt("a", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -371,7 +604,7 @@ mod tests {
     fn minimal_decrypt_upper_key_upper_input() {
         let s = "IJ";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("B", None, None);
+        let mut iter_out = iter_in.decrypt("B", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -381,7 +614,7 @@ mod tests {
     fn minimal_decrypt_lower_key_upper_input() {
         let s = "IJ";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("b", None, None);
+        let mut iter_out = iter_in.decrypt("b", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), None);
@@ -391,7 +624,7 @@ mod tests {
     fn minimal_decrypt_upper_key_lower_input() {
         let s = "ij";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("B", None, None);
+        let mut iter_out = iter_in.decrypt("B", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -401,7 +634,7 @@ mod tests {
     fn minimal_decrypt_lower_key_lower_input() {
         let s = "ij";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("b", None, None);
+        let mut iter_out = iter_in.decrypt("b", None, None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), None);
@@ -411,7 +644,7 @@ mod tests {
     fn bigger_decrypt() {
         let s = "HjJi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("ABC", None, None);
+        let mut iter_out = iter_in.decrypt("ABC", None, None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), Some('H'));
@@ -423,7 +656,7 @@ mod tests {
     fn bigger_decrypt_force_lower() {
         let s = "HjJi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("ABC", Some(ForceCase::ToLower), None);
+        let mut iter_out = iter_in.decrypt("ABC", Some(ForceCase::ToLower), None, None, None);
         assert_eq!(iter_out.next(), Some('h'));
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), Some('h'));
@@ -435,7 +668,7 @@ mod tests {
     fn bigger_decrypt_force_upper() {
         let s = "HjJi";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("ABC", Some(ForceCase::ToUpper), None);
+        let mut iter_out = iter_in.decrypt("ABC", Some(ForceCase::ToUpper), None, None, None);
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('I'));
         assert_eq!(iter_out.next(), Some('H'));
@@ -447,11 +680,205 @@ mod tests {
     fn bigger_decrypt_skip_nonletters() {
         let s = "H.j^J,i";
         let iter_in = s.chars();
-        let mut iter_out = iter_in.decrypt("ABC", None, Some(NonLetterMode::Skip));
+        let mut iter_out = iter_in.decrypt("ABC", None, Some(NonLetterMode::Skip), None, None);
+        assert_eq!(iter_out.next(), Some('H'));
+        assert_eq!(iter_out.next(), Some('i'));
+        assert_eq!(iter_out.next(), Some('H'));
+        assert_eq!(iter_out.next(), Some('i'));
+        assert_eq!(iter_out.next(), None);
+    }
+
+    #[test]
+    fn encrypt_decrypt_digit_alphabet_roundtrip() {
+        let digits = Alphabet::new("0123456789");
+        let s = "0199";
+        let iter_in = s.chars();
+        let cipher: String = iter_in.encrypt("37", None, None, Some(&digits), None).collect();
+        assert_eq!(cipher, "3826");
+
+        let iter_in = cipher.chars();
+        let plain: String = iter_in.decrypt("37", None, None, Some(&digits), None).collect();
+        assert_eq!(plain, s);
+    }
+
+    #[test]
+    fn encrypt_custom_alphabet_passes_through_unknown_chars() {
+        let digits = Alphabet::new("0123456789");
+        let s = "01-99";
+        let iter_in = s.chars();
+        let cipher: String = iter_in.encrypt("37", None, None, Some(&digits), None).collect();
+        assert_eq!(cipher, "38-26");
+    }
+
+    #[test]
+    fn force_case_works_for_lowercase_defined_alphabet() {
+        let lower_alphabet = Alphabet::new("abcdefghijklmnopqrstuvwxyz");
+        let s = "hi";
+        let iter_in = s.chars();
+        let upper: String = iter_in
+            .encrypt("ABC", Some(ForceCase::ToUpper), None, Some(&lower_alphabet), None)
+            .collect();
+        assert_eq!(upper, "HJ");
+
+        let iter_in = s.chars();
+        let lower: String = iter_in
+            .encrypt("ABC", Some(ForceCase::ToLower), None, Some(&lower_alphabet), None)
+            .collect();
+        assert_eq!(lower, "hj");
+    }
+
+    #[test]
+    fn bigger_encrypt_reversed() {
+        let s = "HiHi";
+        let iter_in = s.chars().collect::<Vec<char>>().into_iter();
+        let mut iter_out = iter_in.encrypt("ABC", None, None, None, None).rev();
+        assert_eq!(iter_out.next(), Some('i'));
+        assert_eq!(iter_out.next(), Some('J'));
+        assert_eq!(iter_out.next(), Some('j'));
         assert_eq!(iter_out.next(), Some('H'));
+        assert_eq!(iter_out.next(), None);
+    }
+
+    #[test]
+    fn bigger_decrypt_reversed() {
+        let s = "HjJi";
+        let iter_in = s.chars().collect::<Vec<char>>().into_iter();
+        let mut iter_out = iter_in.decrypt("ABC", None, None, None, None).rev();
         assert_eq!(iter_out.next(), Some('i'));
         assert_eq!(iter_out.next(), Some('H'));
         assert_eq!(iter_out.next(), Some('i'));
+        assert_eq!(iter_out.next(), Some('H'));
+        assert_eq!(iter_out.next(), None);
+    }
+
+    #[test]
+    fn reversed_matches_forward_collected_in_reverse() {
+        let s: Vec<char> = "The Quick Brown Fox".chars().collect();
+        let forward: Vec<char> = s.clone().into_iter().encrypt("KEY", None, None, None, None).collect();
+        let backward: Vec<char> = s.into_iter().encrypt("KEY", None, None, None, None).rev().collect();
+        let mut expected = forward;
+        expected.reverse();
+        assert_eq!(backward, expected);
+    }
+
+    #[test]
+    fn meeting_in_the_middle_does_not_double_shift() {
+        let s = "HiHi".chars().collect::<Vec<char>>().into_iter();
+        let mut iter_out = s.encrypt("ABC", None, None, None, None);
+        assert_eq!(iter_out.next(), Some('H'));
+        assert_eq!(iter_out.next_back(), Some('i'));
+        assert_eq!(iter_out.next(), Some('j'));
+        assert_eq!(iter_out.next_back(), Some('J'));
         assert_eq!(iter_out.next(), None);
+        assert_eq!(iter_out.next_back(), None);
+    }
+
+    #[test]
+    fn size_hint_reflects_remaining_after_mixed_iteration_under_keep() {
+        let s = "HiHi".chars().collect::<Vec<char>>().into_iter();
+        let mut iter_out = s.encrypt("ABC", None, None, None, None);
+        assert_eq!(iter_out.size_hint(), (4, Some(4)));
+        iter_out.next();
+        assert_eq!(iter_out.size_hint(), (3, Some(3)));
+        iter_out.next_back();
+        assert_eq!(iter_out.size_hint(), (2, Some(2)));
+        iter_out.next();
+        iter_out.next_back();
+        assert_eq!(iter_out.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn size_hint_lower_bound_is_zero_under_skip_since_all_chars_could_be_non_letters() {
+        let s = "H-i H+i";
+        let iter_in = s.chars();
+        let iter_out = iter_in.encrypt("ABC", None, Some(NonLetterMode::Skip), None, None);
+        let (lower, upper) = iter_out.size_hint();
+        assert_eq!(lower, 0);
+        assert_eq!(upper, Some(7));
+        assert_eq!(iter_out.count(), 4);
+    }
+
+    #[test]
+    fn autokey_encrypt_decrypt_roundtrip() {
+        let s = "HELLO";
+        let iter_in = s.chars();
+        let cipher: String = iter_in
+            .encrypt("KEY", None, None, None, Some(KeyMode::Autokey))
+            .collect();
+        assert_eq!(cipher, "RIJSS");
+
+        let iter_in = cipher.chars();
+        let plain: String = iter_in
+            .decrypt("KEY", None, None, None, Some(KeyMode::Autokey))
+            .collect();
+        assert_eq!(plain, s);
+    }
+
+    #[test]
+    #[should_panic(expected = "reverse iteration is not supported in KeyMode::Autokey")]
+    fn autokey_next_back_panics() {
+        let s = "HELLO".chars().collect::<Vec<char>>().into_iter();
+        let mut iter_out = s.encrypt("KEY", None, None, None, Some(KeyMode::Autokey));
+        iter_out.next_back();
+    }
+
+    #[test]
+    fn running_key_encrypt_decrypt_roundtrip() {
+        let s = "HELLO";
+        let iter_in = s.chars();
+        let cipher: String = iter_in
+            .encrypt("XMCKL", None, None, None, Some(KeyMode::RunningKey))
+            .collect();
+        assert_eq!(cipher, "EQNVZ");
+
+        let iter_in = cipher.chars();
+        let plain: String = iter_in
+            .decrypt("XMCKL", None, None, None, Some(KeyMode::RunningKey))
+            .collect();
+        assert_eq!(plain, s);
+    }
+
+    #[test]
+    fn running_key_with_spaces_and_punctuation_does_not_panic() {
+        let s = "HELLO THERE";
+        let iter_in = s.chars();
+        let cipher: String = iter_in
+            .encrypt(
+                "THE QUICK BROWN FOX JUMPS",
+                None,
+                None,
+                None,
+                Some(KeyMode::RunningKey),
+            )
+            .collect();
+
+        let iter_in = cipher.chars();
+        let plain: String = iter_in
+            .decrypt(
+                "THE QUICK BROWN FOX JUMPS",
+                None,
+                None,
+                None,
+                Some(KeyMode::RunningKey),
+            )
+            .collect();
+        assert_eq!(plain, s);
+    }
+
+    #[test]
+    #[should_panic(expected = "running key exhausted")]
+    fn running_key_exhaustion_panics() {
+        let s = "HELLO".chars();
+        let _: String = s
+            .encrypt("AB", None, None, None, Some(KeyMode::RunningKey))
+            .collect();
+    }
+
+    #[test]
+    #[should_panic(expected = "key contains no characters present in the alphabet")]
+    fn key_with_no_alphabet_characters_panics() {
+        let digits = Alphabet::new("0123456789");
+        let s = "0199".chars();
+        let _: String = s.encrypt("KEY", None, None, Some(&digits), None).collect();
     }
 }
@@ -24,28 +24,76 @@
 //! ```
 //! Resulting output: `Decrypting gives 'TO EMPOWER EVERYONE'.`
 
+pub mod alphabet;
+pub mod bytes;
+pub mod cryptanalysis;
+pub mod io;
 pub mod iterator;
 
+use alphabet::Alphabet;
 use iterator::{Encrypt, Decrypt};
 
-/// Struct to encrypt or decrypt a string slice using the Vigenère cipher. 
-pub struct Vigenere(String);
+/// Struct to encrypt or decrypt a string slice using the Vigenère cipher.
+pub struct Vigenere {
+    key: String,
+    alphabet: Option<Alphabet>,
+}
 
 impl Vigenere {
-    /// Creates and returns a configured Vigenère encryption/decryption object.  
+    /// Creates and returns a configured Vigenère encryption/decryption object.
     pub fn new(key: &str) -> Self {
-        Self(key.to_owned())
+        Self {
+            key: key.to_owned(),
+            alphabet: None,
+        }
+    }
+
+    /// Creates a Vigenère encryption/decryption object that operates over the given alphabet
+    /// instead of the default uppercase A-Z alphabet, allowing digits, extended Latin, or any
+    /// other ordered character set to be enciphered.
+    pub fn with_alphabet(key: &str, alphabet: &str) -> Self {
+        Self {
+            key: key.to_owned(),
+            alphabet: Some(Alphabet::new(alphabet)),
+        }
     }
 
     /// Encrypts the provided plain text and returns the resulting cipher text.
     pub fn encrypt(&self, plain_text: &str) -> String {
-        plain_text.chars().encrypt(&self.0, None, None).collect()
+        plain_text
+            .chars()
+            .encrypt(&self.key, None, None, self.alphabet.as_ref(), None)
+            .collect()
     }
-    
+
     /// Decrypts the provided cipher text and returns the resulting plain text.
     pub fn decrypt(&self, cipher_text: &str) -> String {
-        cipher_text.chars().decrypt(&self.0, None, None).collect()
-    }    
+        cipher_text
+            .chars()
+            .decrypt(&self.key, None, None, self.alphabet.as_ref(), None)
+            .collect()
+    }
+
+    /// Encrypts arbitrary bytes, not just characters already confined to the alphabet, by first
+    /// packing them into alphabet symbols and then running the ordinary Vigenère transform; see
+    /// [`crate::bytes`] for how the packing works.
+    pub fn encrypt_bytes(&self, data: &[u8]) -> String {
+        bytes::encrypt(data, &self.key, &self.alphabet.clone().unwrap_or_default())
+    }
+
+    /// Reverses [`Vigenere::encrypt_bytes`], returning the original bytes.
+    pub fn decrypt_bytes(&self, cipher_text: &str) -> Vec<u8> {
+        bytes::decrypt(cipher_text, &self.key, &self.alphabet.clone().unwrap_or_default())
+    }
+
+    /// Attempts to recover the key and plain text for `cipher_text` without knowing the key,
+    /// using index-of-coincidence cryptanalysis over the default A-Z alphabet.
+    ///
+    /// Returns candidate `(key, plain_text)` pairs ranked from most to least likely; the list is
+    /// empty if `cipher_text` is too short to analyze.
+    pub fn crack(cipher_text: &str) -> Vec<(String, String)> {
+        cryptanalysis::crack(cipher_text)
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +172,25 @@ mod tests {
         assert_eq!(plain_text, "TO EMPOWER EVERY ONE");
     }
 
+    #[test]
+    fn encrypt_decrypt_with_digit_alphabet() {
+        let cypher = Vigenere::with_alphabet("37", "0123456789");
+
+        let cipher_text = cypher.encrypt("0199");
+        assert_eq!(cipher_text, "3826");
+
+        let plain_text = cypher.decrypt(&cipher_text);
+        assert_eq!(plain_text, "0199");
+    }
+
+    #[test]
+    fn encrypt_decrypt_bytes_roundtrip() {
+        let cypher = Vigenere::new("WHYRUST");
+
+        let data = b"\x00\x01binary data, not just letters\xFF";
+        let cipher_text = cypher.encrypt_bytes(data);
+        let plain_text = cypher.decrypt_bytes(&cipher_text);
+
+        assert_eq!(plain_text, data);
+    }
 }